@@ -85,6 +85,31 @@ pub fn char_is_word(ch: char) -> bool {
     ch.is_alphanumeric() || ch == '_'
 }
 
+/// Determine whether a character is an invisible formatting character,
+/// like a zero width space or a bidirectional override.
+#[inline]
+pub fn char_is_invisible(ch: char) -> bool {
+    use unicode_general_category::{get_general_category, GeneralCategory};
+
+    get_general_category(ch) == GeneralCategory::Format
+}
+
+/// Determine whether a character is a non-latin letter that is easily
+/// confused with a latin letter, like the cyrillic `а`.
+#[inline]
+pub fn char_is_confusable(ch: char) -> bool {
+    matches!(
+        ch,
+        // Cyrillic
+        'а' | 'в' | 'е' | 'к' | 'м' | 'н' | 'о' | 'р' | 'с' | 'т' | 'у' | 'х' | 'һ' | 'і' | 'ј'
+            | 'ѕ' | 'ԁ' | 'ԛ' | 'ԝ' | 'ӏ' | 'А' | 'В' | 'Е' | 'К' | 'М' | 'Н' | 'О' | 'Р'
+            | 'С' | 'Т' | 'Х' | 'І' | 'Ј' | 'Ѕ' | 'Ү'
+            // Greek
+            | 'α' | 'ι' | 'κ' | 'ν' | 'ο' | 'ρ' | 'υ' | 'Α' | 'Β' | 'Ε' | 'Ζ' | 'Η' | 'Ι'
+            | 'Κ' | 'Μ' | 'Ν' | 'Ο' | 'Ρ' | 'Τ' | 'Υ' | 'Χ'
+    )
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -134,4 +159,21 @@ fn test_categorize() {
             );
         }
     }
+
+    #[test]
+    fn test_invisible_and_confusable() {
+        for ch in "\u{200B}\u{200D}\u{202E}\u{2066}\u{FEFF}".chars() {
+            assert!(char_is_invisible(ch), "Testing {:?}", ch);
+        }
+        for ch in "a1 _\té".chars() {
+            assert!(!char_is_invisible(ch), "Testing {:?}", ch);
+        }
+
+        for ch in "аеорсхАВЕНРΤορ".chars() {
+            assert!(char_is_confusable(ch), "Testing {:?}", ch);
+        }
+        for ch in "aeopcxABEHPTop1ǎб".chars() {
+            assert!(!char_is_confusable(ch), "Testing {:?}", ch);
+        }
+    }
 }
@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 use tree_sitter::Node;
 
 use crate::{Rope, Syntax};
@@ -43,6 +45,55 @@ pub fn find_matching_bracket_fuzzy(syntax: &Syntax, doc: &Rope, pos: usize) -> O
     find_pair(syntax, doc, pos, true)
 }
 
+// Returns the char ranges of the tag names of the tag pair under cursor.
+//
+// If the cursor is on the name of an opening or closing tag (as parsed
+// by the HTML grammar), the ranges of both the opening and the
+// closing tag name are returned in document order. If the cursor is on
+// the name of a self-closing tag, only the range of that name is returned.
+//
+// If the cursor is not on a tag name or the tag is not closed, an empty
+// `Vec` is returned.
+#[must_use]
+pub fn find_matching_tag(syntax: &Syntax, doc: &Rope, pos: usize) -> Vec<Range<usize>> {
+    if pos >= doc.len_chars() {
+        return Vec::new();
+    }
+    find_tag_names(syntax, doc, pos).unwrap_or_default()
+}
+
+fn find_tag_names(syntax: &Syntax, doc: &Rope, pos: usize) -> Option<Vec<Range<usize>>> {
+    let tree = syntax.tree();
+    let pos = doc.char_to_byte(pos);
+
+    let name = tree.root_node().named_descendant_for_byte_range(pos, pos)?;
+    if name.kind() != "tag_name" {
+        return None;
+    }
+    let char_range =
+        |node: Node| doc.byte_to_char(node.start_byte())..doc.byte_to_char(node.end_byte());
+
+    let tag = name.parent()?;
+    if tag.kind() == "self_closing_tag" {
+        return Some(vec![char_range(name)]);
+    }
+
+    let element = tag.parent()?;
+    let start_tag = element.named_child(0)?;
+    let end_tag = element.named_child(element.named_child_count().checked_sub(1)?)?;
+    if start_tag.kind() != "start_tag" || end_tag.kind() != "end_tag" {
+        return None;
+    }
+
+    let start_name = start_tag.named_child(0)?;
+    let end_name = end_tag.named_child(0)?;
+    if start_name.kind() != "tag_name" || end_name.kind() != "tag_name" {
+        return None;
+    }
+
+    Some(vec![char_range(start_name), char_range(end_name)])
+}
+
 fn find_pair(syntax: &Syntax, doc: &Rope, pos: usize, traverse_parents: bool) -> Option<usize> {
     let tree = syntax.tree();
     let pos = doc.char_to_byte(pos);
@@ -90,3 +141,62 @@ fn surrounding_bytes(doc: &Rope, node: &Node) -> Option<(usize, usize)> {
 
     Some((start_byte, end_byte))
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::syntax::{Configuration, HighlightConfiguration, Loader};
+    use helix_loader::grammar::get_language;
+
+    fn html_syntax(source: &Rope) -> Syntax {
+        let loader = Loader::new(Configuration { language: vec![] });
+        let language = get_language("html").unwrap();
+        let config = HighlightConfiguration::new(language, "", "", "").unwrap();
+        Syntax::new(source, Arc::new(config), Arc::new(loader))
+    }
+
+    #[test]
+    fn test_find_matching_tag() {
+        let doc = Rope::from("<div>text</div>");
+        let syntax = html_syntax(&doc);
+
+        // on the opening and the closing tag name
+        assert_eq!(find_matching_tag(&syntax, &doc, 2), vec![1..4, 11..14]);
+        assert_eq!(find_matching_tag(&syntax, &doc, 12), vec![1..4, 11..14]);
+        // not on a tag name
+        assert!(find_matching_tag(&syntax, &doc, 0).is_empty());
+        assert!(find_matching_tag(&syntax, &doc, 6).is_empty());
+        assert!(find_matching_tag(&syntax, &doc, doc.len_chars()).is_empty());
+    }
+
+    #[test]
+    fn test_find_matching_tag_self_closing() {
+        let doc = Rope::from("<p><img/></p>");
+        let syntax = html_syntax(&doc);
+
+        assert_eq!(find_matching_tag(&syntax, &doc, 5), vec![4..7]);
+    }
+
+    #[test]
+    fn test_find_matching_tag_unclosed() {
+        let doc = Rope::from("<div>text");
+        let syntax = html_syntax(&doc);
+
+        assert!(find_matching_tag(&syntax, &doc, 2).is_empty());
+    }
+
+    #[test]
+    fn test_find_matching_tag_nested() {
+        let doc = Rope::from("<div><span>x</span></div>");
+        let syntax = html_syntax(&doc);
+
+        // the outer pair
+        assert_eq!(find_matching_tag(&syntax, &doc, 1), vec![1..4, 21..24]);
+        assert_eq!(find_matching_tag(&syntax, &doc, 23), vec![1..4, 21..24]);
+        // the inner pair
+        assert_eq!(find_matching_tag(&syntax, &doc, 7), vec![6..10, 14..18]);
+        assert_eq!(find_matching_tag(&syntax, &doc, 17), vec![6..10, 14..18]);
+    }
+}
@@ -1,5 +1,6 @@
 use std::cmp::min;
 
+use helix_core::chars::char_is_invisible;
 use helix_core::doc_formatter::{DocumentFormatter, GraphemeSource, TextFormat};
 use helix_core::graphemes::Grapheme;
 use helix_core::str_utils::char_to_byte_idx;
@@ -7,7 +8,7 @@
 use helix_core::syntax::HighlightEvent;
 use helix_core::text_annotations::TextAnnotations;
 use helix_core::{visual_offset_from_block, Position, RopeSlice};
-use helix_view::editor::{WhitespaceConfig, WhitespaceRenderValue};
+use helix_view::editor::{UnicodeHighlight, WhitespaceConfig, WhitespaceRenderValue};
 use helix_view::graphics::Rect;
 use helix_view::theme::Style;
 use helix_view::view::ViewPosition;
@@ -311,6 +312,9 @@ pub fn render_text<'t>(
     }
 }
 
+/// Drawn in place of graphemes that consist only of invisible formatting characters
+const INVISIBLE_PLACEHOLDER: &str = "▯";
+
 #[derive(Debug)]
 pub struct TextRenderer<'a> {
     pub surface: &'a mut Surface,
@@ -325,6 +329,8 @@ pub struct TextRenderer<'a> {
     pub tab_width: u16,
     pub starting_indent: usize,
     pub draw_indent_guides: bool,
+    /// Draw invisible formatting characters (like zero width spaces) as `INVISIBLE_PLACEHOLDER`
+    pub draw_invisible: bool,
     pub col_offset: usize,
     pub viewport: Rect,
 }
@@ -388,6 +394,9 @@ pub fn new(
             ),
             text_style,
             draw_indent_guides: editor_config.indent_guides.render,
+            draw_invisible: editor_config
+                .highlight_unicode
+                .contains(&UnicodeHighlight::ZeroWidth),
             viewport,
             col_offset,
         }
@@ -419,6 +428,11 @@ pub fn draw_grapheme(
             // TODO special rendering for other whitespaces?
             Grapheme::Other { ref g } if g == " " => &self.space,
             Grapheme::Other { ref g } if g == "\u{00A0}" => &self.nbsp,
+            Grapheme::Other { ref g }
+                if self.draw_invisible && g.chars().all(char_is_invisible) =>
+            {
+                INVISIBLE_PLACEHOLDER
+            }
             Grapheme::Other { ref g } => &*g,
             Grapheme::Newline => &self.newline,
         };
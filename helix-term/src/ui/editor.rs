@@ -11,24 +11,33 @@
 };
 
 use helix_core::{
+    chars::{char_is_confusable, char_is_invisible, char_is_word},
+    find_first_non_whitespace_char,
     graphemes::{
         ensure_grapheme_boundary_next_byte, next_grapheme_boundary, prev_grapheme_boundary,
     },
+    line_ending::line_end_char_index,
     movement::Direction,
-    syntax::{self, HighlightEvent},
+    regex::{self, Regex, RegexBuilder},
+    syntax::{self, Highlight, HighlightEvent},
     text_annotations::TextAnnotations,
+    tree_sitter::Node,
     unicode::width::UnicodeWidthStr,
-    visual_offset_from_block, Position, Range, Selection, Transaction,
+    visual_offset_from_block, Position, Range, RopeSlice, Selection, Syntax, Transaction,
 };
 use helix_view::{
     document::{Mode, SCRATCH_BUFFER_NAME},
-    editor::{CompleteAction, CursorShapeConfig},
+    editor::{
+        CommitMessageHintsConfig, CompleteAction, CursorShapeConfig, LspConfig,
+        SectionDividersConfig, UnicodeHighlight, WordHighlightConfig,
+    },
     graphics::{Color, CursorKind, Modifier, Rect, Style},
+    gutter::DiagnosticStyles,
     input::{KeyEvent, MouseButton, MouseEvent, MouseEventKind},
     keyboard::{KeyCode, KeyModifiers},
     Document, Editor, Theme, View,
 };
-use std::{num::NonZeroUsize, path::PathBuf, rc::Rc};
+use std::{borrow::Cow, num::NonZeroUsize, path::PathBuf, rc::Rc};
 
 use tui::buffer::Buffer as Surface;
 
@@ -91,42 +100,36 @@ pub fn render_view(
         let mut line_decorations: Vec<Box<dyn LineDecoration>> = Vec::new();
         let mut translated_positions: Vec<TranslatedPosition> = Vec::new();
 
-        // DAP: Highlight current stack frame position
-        let stack_frame = editor.debugger.as_ref().and_then(|debugger| {
-            if let (Some(frame), Some(thread_id)) = (debugger.active_frame, debugger.thread_id) {
-                debugger
-                    .stack_frames
-                    .get(&thread_id)
-                    .and_then(|bt| bt.get(frame))
-            } else {
-                None
-            }
-        });
-        if let Some(frame) = stack_frame {
-            if doc.path().is_some()
-                && frame
-                    .source
-                    .as_ref()
-                    .and_then(|source| source.path.as_ref())
-                    == doc.path()
-            {
-                let line = frame.line - 1; // convert to 0-indexing
-                let style = theme.get("ui.highlight");
-                let line_decoration = move |renderer: &mut TextRenderer, pos: LinePos| {
-                    if pos.doc_line != line {
-                        return;
-                    }
-                    renderer
-                        .surface
-                        .set_style(Rect::new(area.x, pos.visual_line, area.width, 1), style);
-                };
+        if is_focused && config.cursorline {
+            line_decorations.push(Self::cursorline_decorator(doc, view, theme))
+        }
 
-                line_decorations.push(Box::new(line_decoration));
+        if config.diagnostics_indicator {
+            line_decorations.push(Self::diagnostics_indicator_decorator(doc, view, theme));
+        }
+
+        if let Some(min_lines) = config.dim_header_comments {
+            if let Some(line_decoration) = Self::header_comment_decorator(doc, view, min_lines) {
+                line_decorations.push(line_decoration);
             }
         }
 
-        if is_focused && config.cursorline {
-            line_decorations.push(Self::cursorline_decorator(doc, view, theme))
+        if let Some(line_decoration) =
+            Self::section_divider_decorator(doc, view, theme, &config.section_dividers)
+        {
+            line_decorations.push(line_decoration);
+        }
+
+        if let Some(line_decoration) =
+            Self::commit_subject_length_decorator(doc, view, theme, &config.commit_message_hints)
+        {
+            line_decorations.push(line_decoration);
+        }
+
+        // DAP: Highlight current stack frame position.
+        // This is added after the cursorline so that it takes precedence on the cursor's line.
+        if let Some(line_decoration) = Self::debug_frame_decorator(editor, doc, view, theme) {
+            line_decorations.push(line_decoration);
         }
 
         if is_focused && config.cursorcolumn {
@@ -154,7 +157,56 @@ pub fn render_view(
             highlights = Box::new(syntax::merge(highlights, diagnostic));
         }
 
+        let unicode_highlights = Self::doc_unicode_highlights(
+            doc,
+            view.offset.anchor,
+            inner.height,
+            theme,
+            &config.highlight_unicode,
+        );
+        if !unicode_highlights.is_empty() {
+            highlights = Box::new(syntax::merge(highlights, unicode_highlights));
+        }
+
+        if let Some(group_size) = config.digit_grouping {
+            let digit_groups = Self::doc_digit_group_highlights(
+                doc,
+                view.offset.anchor,
+                inner.height,
+                theme,
+                group_size,
+            );
+            if !digit_groups.is_empty() {
+                highlights = Box::new(syntax::merge(highlights, digit_groups));
+            }
+        }
+
+        let commit_message_highlights = Self::doc_commit_message_highlights(
+            doc,
+            view.offset.anchor,
+            inner.height,
+            theme,
+            &config.commit_message_hints,
+        );
+        if !commit_message_highlights.is_empty() {
+            highlights = Box::new(syntax::merge(highlights, commit_message_highlights));
+        }
+
         let highlights: Box<dyn Iterator<Item = HighlightEvent>> = if is_focused {
+            let word_highlights =
+                Self::doc_word_highlights(doc, view, inner.height, theme, &config.word_highlight);
+            if !word_highlights.is_empty() {
+                highlights = Box::new(syntax::merge(highlights, word_highlights));
+            }
+            let active_argument = Self::doc_active_argument_highlights(view, doc, theme);
+            if !active_argument.is_empty() {
+                highlights = Box::new(syntax::merge(highlights, active_argument));
+            }
+            let node_under_cursor =
+                Self::doc_node_highlights(view, doc, theme, config.node_highlight_max_lines);
+            if !node_under_cursor.is_empty() {
+                highlights = Box::new(syntax::merge(highlights, node_under_cursor));
+            }
             let highlights = syntax::merge(
                 highlights,
                 Self::doc_selection_highlights(
@@ -222,7 +274,7 @@ pub fn render_view(
             }
         }
 
-        Self::render_diagnostics(doc, view, inner, surface, theme);
+        Self::render_diagnostics(doc, view, inner, surface, theme, &config.lsp);
 
         let statusline_area = view
             .area
@@ -270,18 +322,9 @@ pub fn overlay_syntax_highlights(
         text_annotations: &TextAnnotations,
     ) -> Vec<(usize, std::ops::Range<usize>)> {
         let text = doc.text().slice(..);
-        let row = text.char_to_line(anchor.min(text.len_chars()));
-
-        let range = {
-            // Calculate viewport byte ranges:
-            // Saturating subs to make it inclusive zero indexing.
-            let last_line = text.len_lines().saturating_sub(1);
-            let last_visible_line = (row + height as usize).saturating_sub(1).min(last_line);
-            let start = text.line_to_byte(row.min(last_line));
-            let end = text.line_to_byte(last_visible_line + 1);
-
-            start..end
-        };
+        // Calculate viewport byte ranges
+        let range = visible_char_range(text, anchor, height);
+        let range = text.char_to_byte(range.start)..text.char_to_byte(range.end);
 
         text_annotations.collect_overlay_highlights(range)
     }
@@ -296,18 +339,9 @@ pub fn doc_syntax_highlights<'doc>(
         _theme: &Theme,
     ) -> Box<dyn Iterator<Item = HighlightEvent> + 'doc> {
         let text = doc.text().slice(..);
-        let row = text.char_to_line(anchor.min(text.len_chars()));
-
-        let range = {
-            // Calculate viewport byte ranges:
-            // Saturating subs to make it inclusive zero indexing.
-            let last_line = text.len_lines().saturating_sub(1);
-            let last_visible_line = (row + height as usize).saturating_sub(1).min(last_line);
-            let start = text.line_to_byte(row.min(last_line));
-            let end = text.line_to_byte(last_visible_line + 1);
-
-            start..end
-        };
+        // Calculate viewport byte ranges
+        let range = visible_char_range(text, anchor, height);
+        let range = text.char_to_byte(range.start)..text.char_to_byte(range.end);
 
         match doc.syntax() {
             Some(syntax) => {
@@ -344,8 +378,8 @@ pub fn doc_syntax_highlights<'doc>(
     pub fn doc_diagnostics_highlights(
         doc: &Document,
         theme: &Theme,
-    ) -> [Vec<(usize, std::ops::Range<usize>)>; 5] {
-        use helix_core::diagnostic::Severity;
+    ) -> [Vec<(usize, std::ops::Range<usize>)>; 6] {
+        use helix_core::diagnostic::{DiagnosticTag, Severity};
         let get_scope_of = |scope| {
             theme
             .find_scope_index_exact(scope)
@@ -365,19 +399,30 @@ pub fn doc_diagnostics_highlights(
         let error = get_scope_of("diagnostic.error");
         let r#default = get_scope_of("diagnostic"); // this is a bit redundant but should be fine
 
+        // deprecated symbols are only styled differently if the theme defines it
+        let deprecated = theme.find_scope_index_exact("diagnostic.deprecated");
+
         let mut default_vec: Vec<(usize, std::ops::Range<usize>)> = Vec::new();
         let mut info_vec = Vec::new();
         let mut hint_vec = Vec::new();
         let mut warning_vec = Vec::new();
         let mut error_vec = Vec::new();
+        let mut deprecated_vec = Vec::new();
 
         for diagnostic in doc.diagnostics() {
+            let is_deprecated = diagnostic
+                .tags
+                .iter()
+                .any(|tag| matches!(tag, DiagnosticTag::Deprecated));
+
             // Separate diagnostics into different Vecs by severity.
-            let (vec, scope) = match diagnostic.severity {
-                Some(Severity::Info) => (&mut info_vec, info),
-                Some(Severity::Hint) => (&mut hint_vec, hint),
-                Some(Severity::Warning) => (&mut warning_vec, warning),
-                Some(Severity::Error) => (&mut error_vec, error),
+            // Deprecations replace the severity style (like a strikethrough instead of an underline).
+            let (vec, scope) = match (deprecated, diagnostic.severity) {
+                (Some(deprecated), _) if is_deprecated => (&mut deprecated_vec, deprecated),
+                (_, Some(Severity::Info)) => (&mut info_vec, info),
+                (_, Some(Severity::Hint)) => (&mut hint_vec, hint),
+                (_, Some(Severity::Warning)) => (&mut warning_vec, warning),
+                (_, Some(Severity::Error)) => (&mut error_vec, error),
                 _ => (&mut default_vec, r#default),
             };
 
@@ -396,7 +441,160 @@ pub fn doc_diagnostics_highlights(
             }
         }
 
-        [default_vec, info_vec, hint_vec, warning_vec, error_vec]
+        [
+            default_vec,
+            info_vec,
+            hint_vec,
+            warning_vec,
+            error_vec,
+            deprecated_vec,
+        ]
+    }
+
+    /// Get highlight spans for the visible unicode characters of the given kinds
+    pub fn doc_unicode_highlights(
+        doc: &Document,
+        anchor: usize,
+        height: u16,
+        theme: &Theme,
+        kinds: &[UnicodeHighlight],
+    ) -> Vec<(usize, std::ops::Range<usize>)> {
+        if kinds.is_empty() {
+            return Vec::new();
+        }
+        let scope = match theme
+            .find_scope_index_exact("ui.highlight.unicode")
+            .or_else(|| theme.find_scope_index("diagnostic.warning"))
+        {
+            Some(scope) => scope,
+            None => return Vec::new(),
+        };
+
+        let text = doc.text().slice(..);
+        let std::ops::Range { start, end } = visible_char_range(text, anchor, height);
+
+        let is_highlighted = |ch: char| {
+            kinds.iter().any(|kind| match kind {
+                UnicodeHighlight::NonAscii => !ch.is_ascii(),
+                UnicodeHighlight::ZeroWidth => char_is_invisible(ch),
+                UnicodeHighlight::Confusable => char_is_confusable(ch),
+            })
+        };
+
+        let mut spans: Vec<(usize, std::ops::Range<usize>)> = Vec::new();
+        for (char_idx, ch) in (start..end).zip(text.slice(start..end).chars()) {
+            if !is_highlighted(ch) {
+                continue;
+            }
+            // merge adjacent characters into a single span
+            match spans.last_mut() {
+                Some((_, range)) if range.end == char_idx => range.end += 1,
+                _ => spans.push((scope, char_idx..char_idx + 1)),
+            }
+        }
+
+        spans
+    }
+
+    /// Get highlight spans for every other group of digits in the visible number literals,
+    /// which are recognized by their `constant.numeric` highlight
+    pub fn doc_digit_group_highlights(
+        doc: &Document,
+        anchor: usize,
+        height: u16,
+        theme: &Theme,
+        group_size: usize,
+    ) -> Vec<(usize, std::ops::Range<usize>)> {
+        let (syntax, scope) = match (
+            doc.syntax(),
+            theme
+                .find_scope_index_exact("ui.highlight.digit-group")
+                .or_else(|| theme.find_scope_index("ui.virtual.whitespace")),
+        ) {
+            (Some(syntax), Some(scope)) => (syntax, scope),
+            _ => return Vec::new(),
+        };
+
+        let text = doc.text().slice(..);
+        let range = visible_char_range(text, anchor, height);
+        let range = text.char_to_byte(range.start)..text.char_to_byte(range.end);
+
+        // collect the char ranges of the sources inside of a numeric highlight
+        let scopes = theme.scopes();
+        let mut is_numeric = Vec::new();
+        let mut literals: Vec<std::ops::Range<usize>> = Vec::new();
+        for event in syntax.highlight_iter(text, Some(range), None) {
+            match event {
+                Ok(HighlightEvent::HighlightStart(Highlight(index))) => is_numeric.push(
+                    scopes
+                        .get(index)
+                        .map_or(false, |scope| scope.starts_with("constant.numeric")),
+                ),
+                Ok(HighlightEvent::HighlightEnd) => {
+                    is_numeric.pop();
+                }
+                Ok(HighlightEvent::Source { start, end }) if is_numeric.contains(&true) => {
+                    let start = text.byte_to_char(start);
+                    let end = text.byte_to_char(end);
+                    match literals.last_mut() {
+                        Some(literal) if literal.end == start => literal.end = end,
+                        _ => literals.push(start..end),
+                    }
+                }
+                Ok(_) => (),
+                Err(_) => break,
+            }
+        }
+
+        literals
+            .into_iter()
+            .flat_map(|literal| {
+                let groups = digit_groups(&Cow::from(text.slice(literal.clone())), group_size);
+                groups.into_iter().map(move |group| {
+                    (
+                        scope,
+                        literal.start + group.start..literal.start + group.end,
+                    )
+                })
+            })
+            .collect()
+    }
+
+    /// Get highlight spans for the parts of the lines of a git commit message
+    /// that exceed the configured subject and body lengths.
+    pub fn doc_commit_message_highlights(
+        doc: &Document,
+        anchor: usize,
+        height: u16,
+        theme: &Theme,
+        config: &CommitMessageHintsConfig,
+    ) -> Vec<(usize, std::ops::Range<usize>)> {
+        if !is_commit_message(doc, config) {
+            return Vec::new();
+        }
+        let scope = match theme.find_scope_index("diagnostic.warning") {
+            Some(scope) => scope,
+            None => return Vec::new(),
+        };
+
+        let text = doc.text().slice(..);
+        let row = text.char_to_line(anchor.min(text.len_chars()));
+        let last_visible_line = row + height as usize;
+
+        commit_message_lines(text)
+            .take_while(|&(line, _)| line < last_visible_line)
+            .filter(|&(line, _)| line >= row)
+            .filter_map(|(line, is_subject)| {
+                let limit = if is_subject {
+                    config.subject_limit
+                } else {
+                    config.body_limit
+                };
+                let start = text.line_to_char(line);
+                let end = line_end_char_index(&text, line);
+                (end - start > limit).then(|| (scope, start + limit..end))
+            })
+            .collect()
     }
 
     /// Get highlight spans for selections in a document view.
@@ -500,7 +698,7 @@ pub fn doc_selection_highlights(
         spans
     }
 
-    /// Render brace match, etc (meant for the focused view only)
+    /// Render brace match, tag match, etc (meant for the focused view only)
     pub fn highlight_focused_view_elements(
         view: &View,
         doc: &Document,
@@ -518,10 +716,125 @@ pub fn highlight_focused_view_elements(
                     return vec![(highlight, pos..pos + 1)];
                 }
             }
+
+            // Highlight the names of matching tags. The tag matching relies on the
+            // node names of the HTML grammar, so it is only done in HTML documents.
+            let tags = if doc.language_name() == Some("html") {
+                match_brackets::find_matching_tag(syntax, doc.text(), pos)
+            } else {
+                Vec::new()
+            };
+            if !tags.is_empty() {
+                if let Some(highlight) = theme.find_scope_index_exact("ui.cursor.match") {
+                    return tags.into_iter().map(|range| (highlight, range)).collect();
+                }
+            }
         }
         Vec::new()
     }
 
+    /// Get highlight spans for the visible occurrences of the word under the primary cursor
+    pub fn doc_word_highlights(
+        doc: &Document,
+        view: &View,
+        height: u16,
+        theme: &Theme,
+        config: &WordHighlightConfig,
+    ) -> Vec<(usize, std::ops::Range<usize>)> {
+        let scope = match theme.find_scope_index("ui.highlight.word") {
+            Some(scope) if config.enable => scope,
+            _ => return Vec::new(),
+        };
+
+        let text = doc.text().slice(..);
+        let cursor = doc.selection(view.id).primary().cursor(text);
+        // nothing to highlight if the cursor is on whitespace or punctuation
+        if cursor >= text.len_chars() || !char_is_word(text.char(cursor)) {
+            return Vec::new();
+        }
+        let word_start = (0..cursor)
+            .rev()
+            .take_while(|&idx| char_is_word(text.char(idx)))
+            .last()
+            .unwrap_or(cursor);
+        let word_end = (cursor..text.len_chars())
+            .find(|&idx| !char_is_word(text.char(idx)))
+            .unwrap_or_else(|| text.len_chars());
+        let word = Cow::from(text.slice(word_start..word_end));
+
+        let mut pattern = regex::escape(&word);
+        if config.whole_word {
+            pattern = format!(r"\b{}\b", pattern);
+        }
+        let regex = match RegexBuilder::new(&pattern)
+            .case_insensitive(!config.case_sensitive)
+            .build()
+        {
+            Ok(regex) => regex,
+            Err(_) => return Vec::new(),
+        };
+
+        // Only search the visible lines, analogous to `doc_syntax_highlights`
+        let std::ops::Range { start, end } = visible_char_range(text, view.offset.anchor, height);
+        let start_byte = text.char_to_byte(start);
+        let visible = Cow::from(text.slice(start..end));
+
+        regex
+            .find_iter(&visible)
+            .map(|mat| {
+                let start = text.byte_to_char(start_byte + mat.start());
+                let end = text.byte_to_char(start_byte + mat.end());
+                (scope, start..end)
+            })
+            .collect()
+    }
+
+    /// Get the highlight span for the argument of the innermost call the primary cursor is in
+    pub fn doc_active_argument_highlights(
+        view: &View,
+        doc: &Document,
+        theme: &Theme,
+    ) -> Vec<(usize, std::ops::Range<usize>)> {
+        let (syntax, scope) = match (
+            doc.syntax(),
+            theme.find_scope_index_exact("ui.highlight.argument"),
+        ) {
+            (Some(syntax), Some(scope)) => (syntax, scope),
+            _ => return Vec::new(),
+        };
+
+        let text = doc.text().slice(..);
+        let cursor = doc.selection(view.id).primary().cursor(text);
+        match active_argument(syntax, text, cursor) {
+            Some(range) => vec![(scope, range)],
+            None => Vec::new(),
+        }
+    }
+
+    /// Get the highlight spans for the smallest named syntax node containing the primary cursor.
+    /// Nodes spanning more than `max_lines` lines are only highlighted on their first and last line.
+    pub fn doc_node_highlights(
+        view: &View,
+        doc: &Document,
+        theme: &Theme,
+        max_lines: usize,
+    ) -> Vec<(usize, std::ops::Range<usize>)> {
+        let (syntax, scope) = match (
+            doc.syntax(),
+            theme.find_scope_index_exact("ui.highlight.node"),
+        ) {
+            (Some(syntax), Some(scope)) => (syntax, scope),
+            _ => return Vec::new(),
+        };
+
+        let text = doc.text().slice(..);
+        let cursor = doc.selection(view.id).primary().cursor(text);
+        node_highlight_ranges(syntax, text, cursor, max_lines)
+            .into_iter()
+            .map(|range| (scope, range))
+            .collect()
+    }
+
     /// Render bufferline at the top
     pub fn render_bufferline(editor: &Editor, viewport: Rect, surface: &mut Surface) {
         let scratch = PathBuf::from(SCRATCH_BUFFER_NAME); // default filename to use for scratch buffer
@@ -648,11 +961,12 @@ pub fn render_diagnostics(
         viewport: Rect,
         surface: &mut Surface,
         theme: &Theme,
+        config: &LspConfig,
     ) {
-        use helix_core::diagnostic::Severity;
+        use helix_core::diagnostic::{NumberOrString, Severity};
         use tui::{
             layout::Alignment,
-            text::Text,
+            text::{Span, Text},
             widgets::{Paragraph, Widget, Wrap},
         };
 
@@ -670,6 +984,9 @@ pub fn render_diagnostics(
         let info = theme.get("info");
         let hint = theme.get("hint");
 
+        let width = 100.min(viewport.width);
+        let height = 15.min(viewport.height);
+
         let mut lines = Vec::new();
         let background_style = theme.get("ui.background");
         for diagnostic in diagnostics {
@@ -681,15 +998,32 @@ pub fn render_diagnostics(
                     Some(Severity::Info) => info,
                     Some(Severity::Hint) => hint,
                 });
-            let text = Text::styled(&diagnostic.message, style);
+            let mut text = Text::styled(&diagnostic.message, style);
+
+            if let Some(code) = diagnostic
+                .code
+                .as_ref()
+                .filter(|_| config.display_diagnostic_codes)
+            {
+                let code = match code {
+                    NumberOrString::Number(n) => format!(" {}", n),
+                    NumberOrString::String(s) => format!(" {}", s),
+                };
+                let code = Span::styled(code, style.add_modifier(Modifier::DIM));
+                // only append the code if it doesn't cause the message to wrap
+                if let Some(line) = text.lines.last_mut() {
+                    if line.width() + code.width() <= width as usize {
+                        line.0.push(code);
+                    }
+                }
+            }
+
             lines.extend(text.lines);
         }
 
         let paragraph = Paragraph::new(lines)
             .alignment(Alignment::Right)
             .wrap(Wrap { trim: true });
-        let width = 100.min(viewport.width);
-        let height = 15.min(viewport.height);
         paragraph.render(
             Rect::new(viewport.right() - width, viewport.y + 1, width, height),
             surface,
@@ -739,6 +1073,138 @@ pub fn cursorline_decorator(
         Box::new(line_decoration)
     }
 
+    /// Mark the lines that contain diagnostics in the last column of the view
+    pub fn diagnostics_indicator_decorator<'d>(
+        doc: &'d Document,
+        view: &View,
+        theme: &Theme,
+    ) -> Box<dyn LineDecoration + 'd> {
+        Box::new(DiagnosticsIndicator {
+            doc,
+            viewport: view.inner_area(doc),
+            styles: DiagnosticStyles::new(theme),
+        })
+    }
+
+    /// Dim the comment block at the top of the document (like a license header)
+    /// if it spans more than `min_lines` lines and the primary cursor is outside of it
+    pub fn header_comment_decorator(
+        doc: &Document,
+        view: &View,
+        min_lines: usize,
+    ) -> Option<Box<dyn LineDecoration>> {
+        let text = doc.text().slice(..);
+        let (start_line, end_line) = header_comment_lines(doc.syntax()?, text)?;
+        if end_line - start_line + 1 <= min_lines {
+            return None;
+        }
+        let cursor_line = doc.selection(view.id).primary().cursor_line(text);
+        if (start_line..=end_line).contains(&cursor_line) {
+            return None;
+        }
+
+        // use the dim modifier instead of a color so that the syntax highlighting is retained
+        let style = Style::default().add_modifier(Modifier::DIM);
+        let viewport = view.inner_area(doc);
+
+        let line_decoration = move |renderer: &mut TextRenderer, pos: LinePos| {
+            if !(start_line..=end_line).contains(&pos.doc_line) {
+                return;
+            }
+            let area = Rect::new(viewport.x, viewport.y + pos.visual_line, viewport.width, 1);
+            renderer.surface.set_style(area, style);
+        };
+
+        Some(Box::new(line_decoration))
+    }
+
+    /// Draw a horizontal rule across the lines that divide sections (like `// -----`)
+    pub fn section_divider_decorator<'d>(
+        doc: &'d Document,
+        view: &View,
+        theme: &Theme,
+        config: &SectionDividersConfig,
+    ) -> Option<Box<dyn LineDecoration + 'd>> {
+        if !config.enable {
+            return None;
+        }
+        let glyph_width = config.glyph.width() as u16;
+        if glyph_width == 0 {
+            return None;
+        }
+
+        Some(Box::new(SectionDivider {
+            text: doc.text().slice(..),
+            regex: config.pattern.clone(),
+            glyph: config.glyph.clone(),
+            glyph_width,
+            viewport: view.inner_area(doc),
+            style: theme
+                .try_get_exact("ui.virtual.divider")
+                .unwrap_or_else(|| theme.get("ui.virtual.whitespace")),
+        }))
+    }
+
+    /// Show the length of the subject line of a git commit message at the end of the line
+    pub fn commit_subject_length_decorator(
+        doc: &Document,
+        view: &View,
+        theme: &Theme,
+        config: &CommitMessageHintsConfig,
+    ) -> Option<Box<dyn LineDecoration>> {
+        if !is_commit_message(doc, config) {
+            return None;
+        }
+        let text = doc.text().slice(..);
+        let (line, _) = commit_message_lines(text).next()?;
+        let length = line_end_char_index(&text, line) - text.line_to_char(line);
+        let style = if length > config.subject_limit {
+            theme.get("warning")
+        } else {
+            theme.get("ui.text.inactive")
+        };
+
+        Some(Box::new(CommitSubjectLength {
+            line,
+            length: length.to_string(),
+            viewport: view.inner_area(doc),
+            style,
+        }))
+    }
+
+    /// Apply the highlighting on the line of the stack frame the debugger is stopped at
+    pub fn debug_frame_decorator(
+        editor: &Editor,
+        doc: &Document,
+        view: &View,
+        theme: &Theme,
+    ) -> Option<Box<dyn LineDecoration>> {
+        let debugger = editor.debugger.as_ref()?;
+        let frame = match (debugger.active_frame, debugger.thread_id) {
+            (Some(frame), Some(thread_id)) => debugger.stack_frames.get(&thread_id)?.get(frame)?,
+            _ => return None,
+        };
+        let path = doc.path()?;
+        let frame_path = frame.source.as_ref()?.path.as_ref()?;
+        if frame_path != path {
+            return None;
+        }
+
+        let line = frame.line.checked_sub(1)?; // convert to 0-indexing
+        let style = theme.get("ui.highlight.frameline");
+        let viewport = view.area;
+
+        let line_decoration = move |renderer: &mut TextRenderer, pos: LinePos| {
+            if pos.doc_line != line {
+                return;
+            }
+            let area = Rect::new(viewport.x, viewport.y + pos.visual_line, viewport.width, 1);
+            renderer.surface.set_style(area, style);
+        };
+
+        Some(Box::new(line_decoration))
+    }
+
     /// Apply the highlighting on the columns where a cursor is active
     pub fn highlight_cursorcolumn(
         doc: &Document,
@@ -1446,6 +1912,268 @@ fn cursor(&self, _area: Rect, editor: &Editor) -> (Option<Position>, CursorKind)
     }
 }
 
+/// Draws a marker colored by the highest severity in the last column of
+/// the first visual line of every document line that has diagnostics.
+/// This is drawn in the foreground so that the marker is always visible,
+/// even if the line extends past the viewport.
+struct DiagnosticsIndicator<'d> {
+    doc: &'d Document,
+    viewport: Rect,
+    styles: DiagnosticStyles,
+}
+
+impl LineDecoration for DiagnosticsIndicator<'_> {
+    fn render_foreground(&mut self, renderer: &mut TextRenderer, pos: LinePos, _: usize) {
+        if !pos.first_visual_line || self.viewport.width == 0 {
+            return;
+        }
+        if let Some(style) = self.styles.line_style(self.doc, pos.doc_line) {
+            renderer.surface.set_string(
+                self.viewport.right() - 1,
+                self.viewport.y + pos.visual_line,
+                "●",
+                style,
+            );
+        }
+    }
+}
+
+/// Draws a horizontal rule over the first visual line of every document line
+/// matching `regex`. This is drawn in the foreground so that it replaces the
+/// text of the divider and spans the whole viewport regardless of the
+/// horizontal scroll position.
+struct SectionDivider<'d> {
+    text: RopeSlice<'d>,
+    regex: Regex,
+    glyph: String,
+    glyph_width: u16,
+    viewport: Rect,
+    style: Style,
+}
+
+impl LineDecoration for SectionDivider<'_> {
+    fn render_foreground(&mut self, renderer: &mut TextRenderer, pos: LinePos, _: usize) {
+        if !pos.first_visual_line
+            || !self
+                .regex
+                .is_match(&Cow::from(self.text.line(pos.doc_line)))
+        {
+            return;
+        }
+        let y = self.viewport.y + pos.visual_line;
+        let mut x = self.viewport.x;
+        while x + self.glyph_width <= self.viewport.right() {
+            renderer.surface.set_string(x, y, &self.glyph, self.style);
+            x += self.glyph_width;
+        }
+    }
+}
+
+/// Draws the length of the subject line of a commit message right-aligned
+/// on the first visual line of the subject.
+/// This is drawn in the foreground so that it is visible even if the
+/// subject extends past the viewport.
+struct CommitSubjectLength {
+    line: usize,
+    length: String,
+    viewport: Rect,
+    style: Style,
+}
+
+impl LineDecoration for CommitSubjectLength {
+    fn render_foreground(&mut self, renderer: &mut TextRenderer, pos: LinePos, _: usize) {
+        let width = self.length.len() as u16;
+        if pos.doc_line != self.line || !pos.first_visual_line || width >= self.viewport.width {
+            return;
+        }
+        renderer.surface.set_string(
+            self.viewport.right() - width,
+            self.viewport.y + pos.visual_line,
+            &self.length,
+            self.style,
+        );
+    }
+}
+
+/// Returns the char ranges (relative to the start of the literal) of every other group
+/// of `group_size` digits in a number literal. The groups are counted away from the
+/// decimal point, the group next to it is not highlighted. Literals with a radix prefix
+/// (like `0xff`) are not grouped.
+fn digit_groups(literal: &str, group_size: usize) -> Vec<std::ops::Range<usize>> {
+    let chars: Vec<char> = literal.chars().collect();
+    if group_size == 0 || matches!(chars[..], ['0', 'x' | 'X' | 'o' | 'O' | 'b' | 'B', ..]) {
+        return Vec::new();
+    }
+
+    let mut groups = Vec::new();
+    let mut after_point = false;
+    let mut i = 0;
+    while i < chars.len() {
+        if !chars[i].is_ascii_digit() {
+            // the fraction and exponent are grouped from their start
+            after_point |= matches!(chars[i], '.' | 'e' | 'E');
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < chars.len() && chars[i].is_ascii_digit() {
+            i += 1;
+        }
+        let len = i - start;
+
+        let mut run_groups = Vec::new();
+        let mut offset = group_size;
+        while offset < len {
+            let group_end = (offset + group_size).min(len);
+            if after_point {
+                run_groups.push(start + offset..start + group_end);
+            } else {
+                run_groups.push(i - group_end..i - offset);
+            }
+            offset += 2 * group_size;
+        }
+        if !after_point {
+            // the integer part is grouped from its end
+            run_groups.reverse();
+        }
+        groups.extend(run_groups);
+    }
+
+    groups
+}
+
+/// Returns the char range of the lines visible in a view of the given `height`
+/// that starts at the line of `anchor`.
+fn visible_char_range(text: RopeSlice, anchor: usize, height: u16) -> std::ops::Range<usize> {
+    let row = text.char_to_line(anchor.min(text.len_chars()));
+    // Saturating subs to make it inclusive zero indexing.
+    let last_line = text.len_lines().saturating_sub(1);
+    let last_visible_line = (row + height as usize).saturating_sub(1).min(last_line);
+    let start = text.line_to_char(row.min(last_line));
+    let end = text.line_to_char(last_visible_line + 1);
+
+    start..end
+}
+
+/// Returns the char ranges to highlight for the smallest named node containing the char
+/// at `pos`. Nodes spanning more than `max_lines` lines are only highlighted from their
+/// start to the end of their first line and from the indentation of their last line to their end.
+fn node_highlight_ranges(
+    syntax: &Syntax,
+    text: RopeSlice,
+    pos: usize,
+    max_lines: usize,
+) -> Vec<std::ops::Range<usize>> {
+    let byte_start = text.char_to_byte(pos);
+    let byte_end = text.char_to_byte((pos + 1).min(text.len_chars()));
+    let node = match syntax
+        .tree()
+        .root_node()
+        .named_descendant_for_byte_range(byte_start, byte_end)
+    {
+        // highlighting the whole document is not helpful
+        Some(node) if node.parent().is_some() => node,
+        _ => return Vec::new(),
+    };
+
+    let start = text.byte_to_char(node.start_byte());
+    let end = text.byte_to_char(node.end_byte());
+    let start_line = text.char_to_line(start);
+    // the end is exclusive, a node that includes its trailing newline ends on the next line
+    let end_line = text.char_to_line(end.saturating_sub(1).max(start));
+    // a single line is always highlighted completely
+    if end_line - start_line < max_lines.max(1) {
+        return vec![start..end];
+    }
+
+    // mark only the edges of large nodes (like function bodies)
+    // so that they don't cover the whole view
+    let first_line_end = line_end_char_index(&text, start_line);
+    let last_line_start = text.line_to_char(end_line)
+        + find_first_non_whitespace_char(text.line(end_line)).unwrap_or(0);
+    vec![start..first_line_end, last_line_start.min(end)..end]
+}
+
+/// Returns the char range of the argument of the innermost call containing the char at `pos`.
+fn active_argument(syntax: &Syntax, text: RopeSlice, pos: usize) -> Option<std::ops::Range<usize>> {
+    // look up the node of the char under the cursor, an empty range at the end
+    // of an argument would find the argument even if the cursor is on the comma after it
+    let start = text.char_to_byte(pos);
+    let end = text.char_to_byte((pos + 1).min(text.len_chars()));
+    let is_argument_list = |node: &Node| matches!(node.kind(), "arguments" | "argument_list");
+
+    let mut node = syntax
+        .tree()
+        .root_node()
+        .named_descendant_for_byte_range(start, end)?;
+    // the cursor is on a delimiter or whitespace between two arguments
+    if is_argument_list(&node) {
+        return None;
+    }
+    // walk up from the node under the cursor until reaching a direct child
+    // of an argument list, the first one found is the innermost argument
+    while let Some(parent) = node.parent() {
+        if is_argument_list(&parent) {
+            return Some(text.byte_to_char(node.start_byte())..text.byte_to_char(node.end_byte()));
+        }
+        node = parent;
+    }
+    None
+}
+
+/// Returns the first and last line of the block of comments at the top of the document.
+/// The block ends at the first line without a comment, and lines that also contain
+/// code after the end of the block are not part of it.
+fn header_comment_lines(syntax: &Syntax, text: RopeSlice) -> Option<(usize, usize)> {
+    let root = syntax.tree().root_node();
+    let mut cursor = root.walk();
+    let mut nodes = root.children(&mut cursor);
+    let is_comment = |node: &Node| node.kind().contains("comment");
+    let start_line = |node: &Node| text.byte_to_line(node.start_byte());
+    let end_line = |node: &Node| text.byte_to_line(node.end_byte().saturating_sub(1));
+
+    let first = nodes.next().filter(is_comment)?;
+    let mut last_line = end_line(&first);
+    for node in nodes {
+        if is_comment(&node) && start_line(&node) <= last_line + 1 {
+            last_line = end_line(&node);
+            continue;
+        }
+        if start_line(&node) == last_line {
+            // code follows the end of the block on the same line
+            last_line = last_line.checked_sub(1)?;
+        }
+        break;
+    }
+
+    let first_line = start_line(&first);
+    (first_line <= last_line).then(|| (first_line, last_line))
+}
+
+fn is_commit_message(doc: &Document, config: &CommitMessageHintsConfig) -> bool {
+    config.enable && doc.language_name() == Some("git-commit")
+}
+
+/// Yields the index of every line that is part of a git commit message and whether
+/// it is the subject line. Like git, comment lines and the blank lines before the
+/// subject are skipped, and the lines after the scissors line
+/// (`# ------------------------ >8 ------------------------`) are ignored.
+fn commit_message_lines(text: RopeSlice) -> impl Iterator<Item = (usize, bool)> + '_ {
+    let is_comment = |line: &RopeSlice| line.chars().next() == Some('#');
+    let mut lines = text
+        .lines()
+        .enumerate()
+        .take_while(move |(_, line)| !(is_comment(line) && Cow::from(*line).contains(">8")))
+        .filter(move |(_, line)| !is_comment(line));
+    let subject = lines
+        .find(|(_, line)| !line.chars().all(char::is_whitespace))
+        .map(|(line, _)| line);
+    subject
+        .map(|line| (line, true))
+        .into_iter()
+        .chain(lines.map(|(line, _)| (line, false)))
+}
+
 fn canonicalize_key(key: &mut KeyEvent) {
     if let KeyEvent {
         code: KeyCode::Char(_),
@@ -1455,3 +2183,188 @@ fn canonicalize_key(key: &mut KeyEvent) {
         key.modifiers.remove(KeyModifiers::SHIFT)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use helix_core::{
+        syntax::{Configuration, HighlightConfiguration, Loader},
+        Rope,
+    };
+    use helix_loader::grammar::get_language;
+
+    fn rust_syntax(text: &Rope) -> Syntax {
+        let loader = Loader::new(Configuration { language: vec![] });
+        let language = get_language("rust").unwrap();
+        let config = HighlightConfiguration::new(language, "", "", "").unwrap();
+        Syntax::new(text, Arc::new(config), Arc::new(loader))
+    }
+
+    fn header_comment(text: &str) -> Option<(usize, usize)> {
+        let text = Rope::from(text);
+        header_comment_lines(&rust_syntax(&text), text.slice(..))
+    }
+
+    #[test]
+    fn test_digit_groups() {
+        assert_eq!(digit_groups("1000000", 3), vec![1..4]);
+        assert_eq!(digit_groups("12345678", 3), vec![2..5]);
+        assert_eq!(digit_groups("1234567", 2), vec![0..1, 3..5]);
+        // the fraction is grouped from the decimal point
+        assert_eq!(digit_groups("1000000.0000001", 3), vec![1..4, 11..14]);
+        assert_eq!(digit_groups("1000000u64", 3), vec![1..4]);
+        assert_eq!(digit_groups("1_000_000", 3), vec![]);
+        assert_eq!(digit_groups("100", 3), vec![]);
+        assert_eq!(digit_groups("0xffffff", 3), vec![]);
+        assert_eq!(digit_groups("0b10101010", 3), vec![]);
+        assert_eq!(digit_groups("1000000", 0), vec![]);
+    }
+
+    #[test]
+    fn test_node_highlight_ranges() {
+        let text = Rope::from("fn f() {\n    a();\n    b();\n}\n");
+        let syntax = rust_syntax(&text);
+        let ranges =
+            |pos, max_lines| node_highlight_ranges(&syntax, text.slice(..), pos, max_lines);
+
+        // the block spans four lines
+        assert_eq!(ranges(9, 4), vec![7..28]);
+        assert_eq!(ranges(9, 3), vec![7..8, 27..28]);
+        // single line nodes are always highlighted completely
+        assert_eq!(ranges(13, 0), vec![13..14]);
+        assert_eq!(ranges(3, 1), vec![3..4]);
+        // the whole document is not highlighted
+        assert_eq!(ranges(29, 10), vec![]);
+    }
+
+    #[test]
+    fn test_active_argument() {
+        let text = Rope::from("fn f() { foo(a, bar(b, c), d); }");
+        let syntax = rust_syntax(&text);
+        let argument = |pos| active_argument(&syntax, text.slice(..), pos);
+
+        // arguments of the outer call
+        assert_eq!(argument(13), Some(13..14));
+        assert_eq!(argument(27), Some(27..28));
+        // arguments of the nested call
+        assert_eq!(argument(20), Some(20..21));
+        assert_eq!(argument(23), Some(23..24));
+        // the name of the nested call is part of an argument of the outer call
+        assert_eq!(argument(16), Some(16..25));
+        assert_eq!(argument(18), Some(16..25));
+        // on a comma or on whitespace between arguments
+        assert_eq!(argument(14), None);
+        assert_eq!(argument(15), None);
+        assert_eq!(argument(22), None);
+        // outside of any argument list
+        assert_eq!(argument(9), None);
+        assert_eq!(argument(3), None);
+    }
+
+    #[test]
+    fn test_header_comment_lines() {
+        assert_eq!(
+            header_comment("// license\n// more license\nfn main() {}"),
+            Some((0, 1))
+        );
+        // the block ends at a blank line
+        assert_eq!(
+            header_comment("// license\n// more license\n\n//! docs\nfn main() {}"),
+            Some((0, 1))
+        );
+        // code after the end of the block is not part of it
+        assert_eq!(
+            header_comment("/* license\n more license */ fn main() {}"),
+            Some((0, 0))
+        );
+        assert_eq!(header_comment("/* license */ fn main() {}"), None);
+        assert_eq!(header_comment("fn main() {}\n// comment"), None);
+        assert_eq!(header_comment(""), None);
+    }
+
+    fn commit_message(text: &str) -> Vec<(usize, bool)> {
+        let text = Rope::from(text);
+        commit_message_lines(text.slice(..)).collect()
+    }
+
+    #[test]
+    fn test_commit_message_lines() {
+        assert_eq!(
+            commit_message("subject\n\nbody\n# comment\nmore body"),
+            vec![(0, true), (1, false), (2, false), (4, false)]
+        );
+        // comments and blank lines before the subject are skipped
+        assert_eq!(
+            commit_message("# comment\n\n  \nsubject\nbody"),
+            vec![(3, true), (4, false)]
+        );
+        // everything below the scissors line is ignored
+        assert_eq!(
+            commit_message(
+                "subject\n# ------------------------ >8 ------------------------\ndiff --git a/b"
+            ),
+            vec![(0, true)]
+        );
+        assert_eq!(
+            commit_message("\n# Please enter the commit message"),
+            vec![]
+        );
+        assert_eq!(commit_message(""), vec![]);
+    }
+
+    #[test]
+    fn test_deprecated_diagnostics_highlights() {
+        use arc_swap::ArcSwap;
+        use helix_core::diagnostic::{
+            Diagnostic, DiagnosticTag, Range as DiagnosticRange, Severity,
+        };
+        use helix_view::{
+            editor::Config,
+            theme::{BASE16_DEFAULT_THEME, DEFAULT_THEME},
+        };
+
+        let mut doc = Document::from(
+            Rope::from("old_fn();\nnew_fn();"),
+            None,
+            Arc::new(ArcSwap::new(Arc::new(Config::default()))),
+        );
+        let diagnostic = |start, end, line, tags| Diagnostic {
+            range: DiagnosticRange { start, end },
+            line,
+            message: String::new(),
+            severity: Some(Severity::Warning),
+            code: None,
+            tags,
+            source: None,
+            data: None,
+        };
+        doc.set_diagnostics(vec![
+            diagnostic(0, 6, 0, vec![DiagnosticTag::Deprecated]),
+            diagnostic(10, 16, 1, Vec::new()),
+        ]);
+
+        // both default themes cross out deprecated symbols
+        for theme in [&*DEFAULT_THEME, &*BASE16_DEFAULT_THEME] {
+            let [_, _, _, warning, _, deprecated] =
+                EditorView::doc_diagnostics_highlights(&doc, theme);
+
+            let deprecated_scope = theme
+                .find_scope_index_exact("diagnostic.deprecated")
+                .unwrap();
+            assert_eq!(deprecated, vec![(deprecated_scope, 0..6)]);
+            assert!(theme
+                .highlight(deprecated_scope)
+                .add_modifier
+                .contains(Modifier::CROSSED_OUT));
+
+            // diagnostics without the tag keep the style of their severity
+            let warning_scope = theme
+                .find_scope_index_exact("diagnostic.warning")
+                .or_else(|| theme.find_scope_index_exact("diagnostic"))
+                .unwrap();
+            assert_eq!(warning, vec![(warning_scope, 10..16)]);
+        }
+    }
+}
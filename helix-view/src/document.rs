@@ -1179,6 +1179,20 @@ pub fn diagnostics(&self) -> &[Diagnostic] {
         &self.diagnostics
     }
 
+    /// Returns the diagnostics on the given line.
+    pub fn diagnostics_on_line(&self, line: usize) -> impl Iterator<Item = &Diagnostic> {
+        let diagnostics = &self.diagnostics[..];
+        // the diagnostics are sorted by range, so the ones on the same line are adjacent
+        let (before, after) = match diagnostics.binary_search_by_key(&line, |d| d.line) {
+            Ok(index) => diagnostics.split_at(index),
+            Err(_) => (&[][..], &[][..]),
+        };
+        after
+            .iter()
+            .take_while(move |d| d.line == line)
+            .chain(before.iter().rev().take_while(move |d| d.line == line))
+    }
+
     pub fn set_diagnostics(&mut self, diagnostics: Vec<Diagnostic>) {
         self.diagnostics = diagnostics;
         self.diagnostics
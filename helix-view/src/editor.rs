@@ -43,6 +43,7 @@
 use helix_core::Position;
 use helix_core::{
     auto_pairs::AutoPairs,
+    regex::Regex,
     syntax::{self, AutoPairConfig},
     Change,
 };
@@ -106,6 +107,21 @@ fn from(x: Vec<GutterType>) -> Self {
     }
 }
 
+fn deserialize_regex<'de, D>(deserializer: D) -> Result<Regex, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let pattern = String::deserialize(deserializer)?;
+    Regex::new(&pattern).map_err(serde::de::Error::custom)
+}
+
+fn serialize_regex<S>(regex: &Regex, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(regex.as_str())
+}
+
 fn deserialize_gutter_seq_or_struct<'de, D>(deserializer: D) -> Result<GutterConfig, D::Error>
 where
     D: Deserializer<'de>,
@@ -274,6 +290,25 @@ pub struct Config {
     /// Whether to color modes with different colors. Defaults to `false`.
     pub color_modes: bool,
     pub soft_wrap: SoftWrap,
+    /// Highlight occurrences of the word under the cursor.
+    pub word_highlight: WordHighlightConfig,
+    /// Kinds of unicode characters to highlight. Defaults to `[]`.
+    pub highlight_unicode: Vec<UnicodeHighlight>,
+    /// Mark lines with diagnostics in the last column of the view. Defaults to false.
+    pub diagnostics_indicator: bool,
+    /// Dim the comment block at the top of a file (like a license header) if it is
+    /// longer than this many lines and the cursor is outside of it. Defaults to `None` (disabled).
+    pub dim_header_comments: Option<usize>,
+    /// Syntax nodes under the cursor spanning more lines than this are only highlighted
+    /// on their first and last line. Defaults to 10.
+    pub node_highlight_max_lines: usize,
+    /// Highlight every other group of this many digits in long number literals.
+    /// Defaults to `None` (disabled).
+    pub digit_grouping: Option<usize>,
+    /// Horizontal rules drawn over comment lines that divide sections.
+    pub section_dividers: SectionDividersConfig,
+    /// Length hints for git commit messages.
+    pub commit_message_hints: CommitMessageHintsConfig,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -313,6 +348,98 @@ fn default() -> Self {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum UnicodeHighlight {
+    /// Any character outside of the ASCII range
+    NonAscii,
+    /// Invisible formatting characters like zero width spaces and bidirectional overrides.
+    /// These are additionally drawn as a placeholder
+    ZeroWidth,
+    /// Non-latin letters that look like latin letters (like the cyrillic `а`)
+    Confusable,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct WordHighlightConfig {
+    /// Highlight all visible occurrences of the word under the primary cursor. Defaults to false.
+    pub enable: bool,
+    /// Only highlight occurrences with the same case. Defaults to true.
+    pub case_sensitive: bool,
+    /// Only highlight occurrences that are whole words. Defaults to true.
+    pub whole_word: bool,
+}
+
+impl Default for WordHighlightConfig {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            case_sensitive: true,
+            whole_word: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct SectionDividersConfig {
+    /// Draw a horizontal rule across the lines matching `pattern`. Defaults to false.
+    pub enable: bool,
+    /// Regex matching the lines that divide sections.
+    /// Defaults to comments consisting of at least three `-`, `=` or `─`.
+    #[serde(
+        serialize_with = "serialize_regex",
+        deserialize_with = "deserialize_regex"
+    )]
+    pub pattern: Regex,
+    /// Glyph the rule is drawn with. Defaults to `─`.
+    pub glyph: String,
+}
+
+impl Default for SectionDividersConfig {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            pattern: Regex::new(r"^\s*(//+|#+|--|;+|/\*)\s*[-=─]{3,}\s*(\*/)?\s*$").unwrap(),
+            glyph: "─".to_string(),
+        }
+    }
+}
+
+// `Regex` does not implement `PartialEq`, so the patterns are compared by their source
+impl PartialEq for SectionDividersConfig {
+    fn eq(&self, other: &Self) -> bool {
+        self.enable == other.enable
+            && self.pattern.as_str() == other.pattern.as_str()
+            && self.glyph == other.glyph
+    }
+}
+
+impl Eq for SectionDividersConfig {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct CommitMessageHintsConfig {
+    /// Highlight overlong lines and show the length of the subject line
+    /// in git commit messages. Defaults to false.
+    pub enable: bool,
+    /// Maximum length of the subject line. Defaults to 50.
+    pub subject_limit: usize,
+    /// Maximum length of the lines in the message body. Defaults to 72.
+    pub body_limit: usize,
+}
+
+impl Default for CommitMessageHintsConfig {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            subject_limit: 50,
+            body_limit: 72,
+        }
+    }
+}
+
 #[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
 pub struct TerminalConfig {
@@ -377,6 +504,8 @@ pub struct LspConfig {
     pub auto_signature_help: bool,
     /// Display docs under signature help popup
     pub display_signature_help_docs: bool,
+    /// Display the code of a diagnostic (like a lint rule name) after its message
+    pub display_diagnostic_codes: bool,
 }
 
 impl Default for LspConfig {
@@ -386,6 +515,7 @@ fn default() -> Self {
             display_messages: false,
             auto_signature_help: true,
             display_signature_help_docs: true,
+            display_diagnostic_codes: false,
         }
     }
 }
@@ -764,6 +894,14 @@ fn default() -> Self {
             indent_guides: IndentGuidesConfig::default(),
             color_modes: false,
             soft_wrap: SoftWrap::default(),
+            word_highlight: WordHighlightConfig::default(),
+            highlight_unicode: Vec::new(),
+            diagnostics_indicator: false,
+            dim_header_comments: None,
+            node_highlight_max_lines: 10,
+            digit_grouping: None,
+            section_dividers: SectionDividersConfig::default(),
+            commit_message_hints: CommitMessageHintsConfig::default(),
         }
     }
 }
@@ -1678,3 +1816,28 @@ fn inserted_a_new_blank_line(changes: &[Operation], pos: usize, line_end_pos: us
         doc.apply(&transaction, view.id);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_section_divider_pattern() {
+        let pattern = SectionDividersConfig::default().pattern;
+        for divider in [
+            "// ---",
+            "# ===",
+            "-- -----",
+            "  /* ---------- */",
+            "// ─────\n",
+        ] {
+            assert!(pattern.is_match(divider), "{:?} is a divider", divider);
+        }
+        for line in ["---", "# heading", "x -= 1;", "let x = 1; // ---"] {
+            assert!(!pattern.is_match(line), "{:?} is not a divider", line);
+        }
+
+        // invalid patterns are reported when loading the config
+        assert!(toml::from_str::<SectionDividersConfig>("pattern = '('").is_err());
+    }
+}
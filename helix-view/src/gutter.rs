@@ -45,6 +45,39 @@ pub fn width(self, view: &View, doc: &Document) -> usize {
     }
 }
 
+/// The styles lines with diagnostics are marked with, by their highest severity
+#[derive(Debug, Clone, Copy)]
+pub struct DiagnosticStyles {
+    warning: Style,
+    error: Style,
+    info: Style,
+    hint: Style,
+}
+
+impl DiagnosticStyles {
+    pub fn new(theme: &Theme) -> Self {
+        Self {
+            warning: theme.get("warning"),
+            error: theme.get("error"),
+            info: theme.get("info"),
+            hint: theme.get("hint"),
+        }
+    }
+
+    /// Returns the style for the highest severity of the diagnostics on the given line,
+    /// or `None` if there are no diagnostics on that line.
+    pub fn line_style(&self, doc: &Document, line: usize) -> Option<Style> {
+        use helix_core::diagnostic::Severity;
+        let severity = doc.diagnostics_on_line(line).map(|d| d.severity).max()?;
+        Some(match severity {
+            Some(Severity::Error) => self.error,
+            Some(Severity::Warning) | None => self.warning,
+            Some(Severity::Info) => self.info,
+            Some(Severity::Hint) => self.hint,
+        })
+    }
+}
+
 pub fn diagnostic<'doc>(
     _editor: &'doc Editor,
     doc: &'doc Document,
@@ -52,40 +85,16 @@ pub fn diagnostic<'doc>(
     theme: &Theme,
     _is_focused: bool,
 ) -> GutterFn<'doc> {
-    let warning = theme.get("warning");
-    let error = theme.get("error");
-    let info = theme.get("info");
-    let hint = theme.get("hint");
-    let diagnostics = doc.diagnostics();
+    let styles = DiagnosticStyles::new(theme);
 
     Box::new(
         move |line: usize, _selected: bool, first_visual_line: bool, out: &mut String| {
             if !first_visual_line {
                 return None;
             }
-            use helix_core::diagnostic::Severity;
-            if let Ok(index) = diagnostics.binary_search_by_key(&line, |d| d.line) {
-                let after = diagnostics[index..].iter().take_while(|d| d.line == line);
-
-                let before = diagnostics[..index]
-                    .iter()
-                    .rev()
-                    .take_while(|d| d.line == line);
-
-                let diagnostics_on_line = after.chain(before);
-
-                // This unwrap is safe because the iterator cannot be empty as it contains at least the item found by the binary search.
-                let diagnostic = diagnostics_on_line.max_by_key(|d| d.severity).unwrap();
-
-                write!(out, "●").unwrap();
-                return Some(match diagnostic.severity {
-                    Some(Severity::Error) => error,
-                    Some(Severity::Warning) | None => warning,
-                    Some(Severity::Info) => info,
-                    Some(Severity::Hint) => hint,
-                });
-            }
-            None
+            let style = styles.line_style(doc, line)?;
+            write!(out, "●").unwrap();
+            Some(style)
         },
     )
 }